@@ -0,0 +1,262 @@
+//! A growable array backed by a LazyArray.
+
+use crate::{Array, LazyArray};
+use std::ptr;
+
+/// A growable array that uses a [`LazyArray`] for storage.
+///
+/// Works like [`std::vec::Vec`]. `push` appends in amortized O(1) by
+/// doubling capacity whenever the backing storage is full.
+pub struct DynArray<T> {
+    len: usize,
+    array: LazyArray<T>,
+}
+
+impl<T> DynArray<T> {
+    /// Create an empty array that has not allocated yet.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            len: 0,
+            array: Array::lazy(0),
+        }
+    }
+
+    /// Number of initialized elements.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the array holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of elements the backing storage can hold before it needs to
+    /// grow again.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.array.len()
+    }
+
+    /// Reference to the initialized elements as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // Safety: exactly the first `self.len` slots are initialized.
+        unsafe { self.array.assume_init(0, self.len) }
+    }
+
+    /// Append an element to the end of the array.
+    ///
+    /// Doubles capacity (starting from 1) whenever the backing storage is
+    /// full, for amortized O(1) pushes, same as [`std::vec::Vec::push`].
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Element to append.
+    pub fn push(&mut self, value: T) {
+        if self.len == self.array.len() {
+            self.grow();
+        }
+
+        self.array.write(self.len, value);
+        self.len += 1;
+    }
+
+    /// Remove and return the last element, if any.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        // Safety: slot `self.len` was initialized and is now logically
+        // removed by the bookkeeping above.
+        Some(unsafe { self.array.assume_init_read(self.len) })
+    }
+
+    /// Insert an element at `index`, shifting every later element one slot
+    /// to the right.
+    ///
+    /// # Panic
+    ///
+    /// Panics if `index > self.len()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position to insert the element at.
+    /// * `value` - Element to insert.
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len, "index out of bounds");
+        if self.len == self.array.len() {
+            self.grow();
+        }
+
+        // Safety: shifts initialized elements `[index, self.len)` one slot
+        // to the right, which stays inside the backing allocation since it
+        // was just grown if it was at capacity. Walking back to front
+        // avoids clobbering a slot before it is read.
+        unsafe {
+            for i in (index..self.len).rev() {
+                let moved = self.array.assume_init_read(i);
+                self.array.write(i + 1, moved);
+            }
+        }
+
+        self.array.write(index, value);
+        self.len += 1;
+    }
+
+    /// Remove and return the element at `index`, shifting every later
+    /// element one slot to the left.
+    ///
+    /// # Panic
+    ///
+    /// Panics if `index >= self.len()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position of the element to remove.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "index out of bounds");
+
+        // Safety: `index` is within the initialized prefix.
+        let value = unsafe { self.array.assume_init_read(index) };
+
+        // Safety: shifts initialized elements `(index, self.len)` one slot
+        // to the left, which stays inside the initialized prefix.
+        unsafe {
+            for i in index + 1..self.len {
+                let moved = self.array.assume_init_read(i);
+                self.array.write(i - 1, moved);
+            }
+        }
+
+        self.len -= 1;
+        value
+    }
+
+    /// Shorten the array to `len` elements, dropping the rest in place.
+    ///
+    /// Does nothing if `len` is greater than or equal to the current
+    /// length.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Length to shorten the array to.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+
+        // Safety: elements in `[len, self.len)` are initialized.
+        unsafe { self.array.assume_init_drop(len, self.len - len) };
+        self.len = len;
+    }
+
+    /// Grow the backing storage to `max(1, 2 * capacity())`, moving (not
+    /// copying) the initialized prefix into the new allocation.
+    fn grow(&mut self) {
+        let cap = self.array.len();
+        let new_cap = std::cmp::max(1, cap.checked_mul(2).expect("capacity overflow"));
+        let mut array = Array::try_lazy(new_cap).unwrap_or_else(|err| panic!("{err}"));
+
+        // Safety: the first `self.len` slots of `self.array` are
+        // initialized and fit inside the newly, larger allocation.
+        unsafe {
+            ptr::copy_nonoverlapping(self.array.as_ptr(), array.as_mut_ptr(), self.len);
+        }
+
+        // The old backing storage is replaced without running destructors:
+        // every live element was just moved, not copied, into `array`.
+        self.array = array;
+    }
+}
+
+impl<T> Default for DynArray<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for DynArray<T> {
+    fn drop(&mut self) {
+        // Safety: exactly the first `self.len` slots are initialized.
+        unsafe { self.array.assume_init_drop(0, self.len) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{Bytes, Seqno, Zst};
+    use bolero::{TypeGenerator, check, generator};
+    use pastey::paste;
+
+    // Maximum size of inputs in property based tests.
+    const MAX_SIZE: usize = 1024 * 1024; // 1 MB
+
+    #[derive(Debug, Clone, TypeGenerator)]
+    enum Operation<T> {
+        Push(T),
+        Pop,
+        Insert(usize, T),
+        Remove(usize),
+        Truncate(usize),
+    }
+
+    macro_rules! test_dyn_array {
+        ($($type:ty),*) => {
+            paste! {
+                $(
+                    #[test]
+                    fn [<test_dyn_array_ $type:snake>]() {
+                        check!()
+                            .with_max_len(MAX_SIZE)
+                            .with_generator(generator::produce::<Vec<Operation<$type>>>())
+                            .for_each(|operations| {
+                                let mut array = DynArray::new();
+                                let mut oracle = Vec::new();
+
+                                for operation in operations.iter().cloned() {
+                                    match operation {
+                                        Operation::Push(value) => {
+                                            array.push(value.clone());
+                                            oracle.push(value);
+                                        }
+                                        Operation::Pop => {
+                                            assert_eq!(array.pop(), oracle.pop());
+                                        }
+                                        Operation::Insert(index, value) => {
+                                            let index = index % (oracle.len() + 1);
+                                            array.insert(index, value.clone());
+                                            oracle.insert(index, value);
+                                        }
+                                        Operation::Remove(index) => {
+                                            if oracle.is_empty() {
+                                                continue;
+                                            }
+                                            let index = index % oracle.len();
+                                            assert_eq!(array.remove(index), oracle.remove(index));
+                                        }
+                                        Operation::Truncate(len) => {
+                                            let len = len % (oracle.len() + 1);
+                                            array.truncate(len);
+                                            oracle.truncate(len);
+                                        }
+                                    }
+
+                                    assert_eq!(array.as_slice(), oracle.as_slice());
+                                }
+                            });
+                    }
+                )*
+            }
+        };
+    }
+
+    test_dyn_array!(Zst, Seqno, Bytes);
+}
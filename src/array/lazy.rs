@@ -127,6 +127,65 @@ impl<T> LazyArray<T> {
     }
 }
 
+impl<T> LazyArray<T> {
+    /// Initialize a single element.
+    ///
+    /// # Panic
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the element to initialize.
+    /// * `value` - Value to move into the slot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use structures::Array;
+    /// let mut array = Array::lazy(10);
+    ///
+    /// array.write(0, 42);
+    /// assert_eq!(unsafe { array.assume_init(0, 1) }, &[42]);
+    /// ```
+    #[inline]
+    pub fn write(&mut self, index: usize, value: T) -> &mut T {
+        self[index].write(value)
+    }
+
+    /// Read a single initialized element out of the array.
+    ///
+    /// The slot itself is left untouched (still reported as initialized),
+    /// so the caller takes over responsibility for the moved-out value and
+    /// must not read or drop the slot again until it is re-initialized.
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure the element at `index` is indeed
+    /// initialized, and that it is not read or dropped again afterwards.
+    ///
+    /// # Panic
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use structures::Array;
+    /// let mut array = Array::lazy(10);
+    ///
+    /// array.write(0, String::from("hello"));
+    /// let value = unsafe { array.assume_init_read(0) };
+    /// assert_eq!(value, "hello");
+    /// ```
+    #[inline]
+    pub unsafe fn assume_init_read(&self, index: usize) -> T {
+        // Safety: It is the responsibility of the caller to ensure the slot
+        // is actually initialized, and that it is not read again afterwards.
+        unsafe { std::ptr::read(self[index].as_ptr()) }
+    }
+}
+
 impl<T: Clone> LazyArray<T> {
     /// Initialize a slice of elements with another slice.
     ///
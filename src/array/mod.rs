@@ -0,0 +1,216 @@
+//! Heap allocated arrays, implemented from scratch on top of the global allocator.
+
+pub mod dynamic;
+pub mod ring;
+
+mod lazy;
+
+use crate::TryReserveError;
+use std::alloc::{self, Layout};
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+use std::ptr::{self, NonNull};
+
+/// An array that can hold uninitialized elements.
+///
+/// Elements can be safely aliased only after initialization, which is the
+/// responsibility of the caller and thus requires use of unsafe. The caller
+/// is also responsible for dropping initialized elements, which requires
+/// unsafe too.
+pub struct LazyArray<T> {
+    ptr: NonNull<MaybeUninit<T>>,
+    len: usize,
+}
+
+// Safety: `LazyArray<T>` owns its backing allocation exclusively, same as
+// `Box<[MaybeUninit<T>]>` would.
+unsafe impl<T: Send> Send for LazyArray<T> {}
+unsafe impl<T: Sync> Sync for LazyArray<T> {}
+
+impl<T> Deref for LazyArray<T> {
+    type Target = [MaybeUninit<T>];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // Safety: `ptr` was allocated to hold exactly `len` elements.
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> DerefMut for LazyArray<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // Safety: `ptr` was allocated to hold exactly `len` elements.
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> Drop for LazyArray<T> {
+    fn drop(&mut self) {
+        // This does not run destructors: it is up to the caller to have
+        // already dropped any initialized elements (see `assume_init_drop`).
+        if self.len == 0 || std::mem::size_of::<T>() == 0 {
+            return;
+        }
+
+        // Safety: this is exactly the layout `Array::try_lazy` allocated.
+        let layout = Layout::array::<T>(self.len).unwrap();
+        unsafe { alloc::dealloc(self.ptr.as_ptr().cast(), layout) };
+    }
+}
+
+/// A fixed length collection of elements held in a contiguous heap
+/// allocation.
+///
+/// This is the heap allocated equivalent of a stack allocated array. Since
+/// this data structure is heap allocated, length of the array can be
+/// provided at runtime. `Array` doubles as a namespace for the allocating
+/// constructors of both fully initialized arrays and the lazily
+/// initialized [`LazyArray`] storage other structures in the crate are
+/// built on.
+pub struct Array<T> {
+    ptr: NonNull<T>,
+    len: usize,
+}
+
+// Safety: `Array<T>` owns its backing allocation exclusively, same as
+// `Box<[T]>` would.
+unsafe impl<T: Send> Send for Array<T> {}
+unsafe impl<T: Sync> Sync for Array<T> {}
+
+impl<T> Deref for Array<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // Safety: `ptr` was allocated to hold exactly `len` initialized elements.
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> DerefMut for Array<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // Safety: `ptr` was allocated to hold exactly `len` initialized elements.
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> Drop for Array<T> {
+    fn drop(&mut self) {
+        if self.len == 0 {
+            return;
+        }
+
+        // Safety: `ptr` holds exactly `len` initialized elements, none of
+        // which have been dropped yet.
+        unsafe { ptr::drop_in_place(self.deref_mut() as *mut [T]) };
+
+        if std::mem::size_of::<T>() != 0 {
+            // Safety: this is exactly the layout `try_with_len` allocated.
+            let layout = Layout::array::<T>(self.len).unwrap();
+            unsafe { alloc::dealloc(self.ptr.as_ptr().cast(), layout) };
+        }
+    }
+}
+
+impl<T> Array<T> {
+    /// Allocate lazily initialized storage for `len` elements.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the allocator cannot satisfy the request. See
+    /// [`Array::try_lazy`] for a fallible version.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Number of elements the storage can hold.
+    #[inline]
+    pub fn lazy(len: usize) -> LazyArray<T> {
+        Self::try_lazy(len).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Allocate lazily initialized storage for `len` elements, without
+    /// panicking if the allocation fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Number of elements the storage can hold.
+    pub fn try_lazy(len: usize) -> Result<LazyArray<T>, TryReserveError> {
+        // Zero sized types and empty arrays never actually allocate: the
+        // global allocator forbids zero-sized layouts.
+        if len == 0 || std::mem::size_of::<T>() == 0 {
+            return Ok(LazyArray {
+                ptr: NonNull::dangling(),
+                len,
+            });
+        }
+
+        // `Layout::array` itself rejects a size that would overflow
+        // `isize::MAX`, which is exactly the guarantee we want here.
+        let layout = Layout::array::<T>(len).map_err(|_| TryReserveError::CapacityOverflow)?;
+
+        // Safety: `layout` has a non-zero size, checked above.
+        let ptr = unsafe { alloc::alloc(layout) };
+        match NonNull::new(ptr) {
+            Some(ptr) => Ok(LazyArray {
+                ptr: ptr.cast(),
+                len,
+            }),
+            None => Err(TryReserveError::AllocError(layout)),
+        }
+    }
+}
+
+impl<T: Default> Array<T> {
+    /// Allocate storage for `len` elements, filling each with `T::default()`.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the allocator cannot satisfy the request. See
+    /// [`Array::try_with_len`] for a fallible version.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Number of elements to allocate and initialize.
+    #[inline]
+    pub fn with_len(len: usize) -> Self {
+        Self::try_with_len(len).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Allocate storage for `len` elements, filling each with `T::default()`,
+    /// without panicking if the allocation fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Number of elements to allocate and initialize.
+    pub fn try_with_len(len: usize) -> Result<Self, TryReserveError> {
+        let mut array = Self::try_lazy(len)?;
+        for index in 0..len {
+            array.write(index, T::default());
+        }
+
+        // Safety: every slot in `[0, len)` was just initialized above.
+        Ok(unsafe { array.into_array() })
+    }
+}
+
+impl<T> LazyArray<T> {
+    /// Convert fully initialized lazy storage into a plain [`Array`].
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure every element in `[0, len)` is initialized.
+    unsafe fn into_array(self) -> Array<T> {
+        let array = Array {
+            ptr: self.ptr.cast(),
+            len: self.len,
+        };
+
+        // The allocation now belongs to `array`; don't run `LazyArray`'s
+        // destructor (which would be a no-op for drop purposes anyway,
+        // since it never drops elements, but would double-free otherwise).
+        std::mem::forget(self);
+        array
+    }
+}
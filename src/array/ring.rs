@@ -1,6 +1,6 @@
 //! A ring buffer backed by an Array.
 
-use crate::{Array, LazyArray};
+use crate::{Array, LazyArray, TryReserveError};
 
 /// A ring buffer that uses a [`LazyArray`] for storage.
 ///
@@ -16,16 +16,32 @@ pub struct RingArray<T> {
 impl<T> RingArray<T> {
     /// Create a ring buffer with pre-allocated capacity.
     ///
+    /// # Panic
+    ///
+    /// Panics if the allocator cannot satisfy the request. See
+    /// [`RingArray::try_with_capacity`] for a fallible version.
+    ///
     /// # Arguments
     ///
     /// * `capacity` - Maximum number of elements ring buffer can hold.
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
-        Self {
+        Self::try_with_capacity(capacity).unwrap()
+    }
+
+    /// Create a ring buffer with pre-allocated capacity, without panicking
+    /// if the allocation fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Maximum number of elements ring buffer can hold.
+    #[inline]
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        Ok(Self {
             len: 0,
             next: 0,
-            array: Array::lazy(capacity),
-        }
+            array: Array::try_lazy(capacity)?,
+        })
     }
 
     /// Reference to elements held as a pair of slices.
@@ -35,18 +51,23 @@ impl<T> RingArray<T> {
     /// are ordered across slices.
     #[inline]
     pub fn as_slices(&self) -> (&[T], &[T]) {
-        // If the ring buffer has not wrapper around, it doesn't have a tail.
-        // Everything is just one contiguous sequence of elements.
         let cap = self.array.len();
-        if self.len < cap {
-            let head = unsafe { self.array.assume_init(0, self.len) };
+        if cap == 0 || self.len == 0 {
+            return (Default::default(), Default::default());
+        }
+
+        // If the head run doesn't reach the end of the backing array, it
+        // doesn't have a tail. Everything is just one contiguous sequence.
+        let head = self.next;
+        if head + self.len <= cap {
+            let head = unsafe { self.array.assume_init(head, self.len) };
             return (head, Default::default());
         }
 
         unsafe {
-            let head = self.array.assume_init(self.next, cap - self.next);
-            let tail = self.array.assume_init(0, self.next);
-            (head, tail)
+            let head_slice = self.array.assume_init(head, cap - head);
+            let tail_slice = self.array.assume_init(0, head + self.len - cap);
+            (head_slice, tail_slice)
         }
     }
 
@@ -58,6 +79,325 @@ impl<T> RingArray<T> {
         let (head, tail) = self.as_slices();
         head.iter().chain(tail.iter())
     }
+
+    /// Mutable reference to elements held as a pair of slices.
+    ///
+    /// Same layout guarantees as [`RingArray::as_slices`], but lets callers
+    /// mutate buffered elements in place.
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let cap = self.array.len();
+        if cap == 0 || self.len == 0 {
+            return (Default::default(), Default::default());
+        }
+
+        let head = self.next;
+        if head + self.len <= cap {
+            let head = unsafe { self.array.assume_init_mut(head, self.len) };
+            return (head, Default::default());
+        }
+
+        let head_len = cap - head;
+        let tail_len = head + self.len - cap;
+
+        // Safety: `head` and `0` address disjoint physical ranges of the
+        // same backing allocation (the head run never reaches past `cap`,
+        // the tail run never reaches past `head`), so splitting them into
+        // two mutable slices through raw pointers does not alias. `T` and
+        // `MaybeUninit<T>` share layout, and both ranges are initialized.
+        unsafe {
+            let base = self.array.as_mut_ptr().cast::<T>();
+            let head_slice = std::slice::from_raw_parts_mut(base.add(head), head_len);
+            let tail_slice = std::slice::from_raw_parts_mut(base, tail_len);
+            (head_slice, tail_slice)
+        }
+    }
+
+    /// Mutable iterator through contents of the ring buffer.
+    ///
+    /// Elements are ordered based on the insertion order of elements.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let (head, tail) = self.as_mut_slices();
+        head.iter_mut().chain(tail.iter_mut())
+    }
+
+    /// Reference to the oldest element in the ring buffer.
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // Safety: the slot at the head is initialized whenever `len > 0`.
+        Some(&unsafe { self.array.assume_init(self.next, 1) }[0])
+    }
+
+    /// Reference to the newest element in the ring buffer.
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let index = self.back_index();
+        // Safety: the slot at `index` is initialized whenever `len > 0`.
+        Some(&unsafe { self.array.assume_init(index, 1) }[0])
+    }
+
+    /// Append an element to the back of the ring buffer.
+    ///
+    /// If the ring buffer is at capacity, the oldest element is evicted
+    /// (and dropped) to make room.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Element to append.
+    pub fn push_back(&mut self, value: T) {
+        let cap = self.array.len();
+        if cap == 0 {
+            return;
+        }
+
+        let index = (self.next + self.len) % cap;
+        if self.len == cap {
+            // Safety: `index` wraps back onto the head, the oldest slot,
+            // about to be clobbered by the write below.
+            unsafe { self.array.assume_init_drop(index, 1) };
+            self.next = (self.next + 1) % cap;
+        } else {
+            self.len += 1;
+        }
+
+        self.array.write(index, value);
+    }
+
+    /// Prepend an element to the front of the ring buffer.
+    ///
+    /// If the ring buffer is at capacity, the newest element is evicted
+    /// (and dropped) to make room.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Element to prepend.
+    pub fn push_front(&mut self, value: T) {
+        let cap = self.array.len();
+        if cap == 0 {
+            return;
+        }
+
+        let index = (self.next + cap - 1) % cap;
+        if self.len == cap {
+            // Safety: `index` is the back slot, about to be clobbered below.
+            unsafe { self.array.assume_init_drop(index, 1) };
+        } else {
+            self.len += 1;
+        }
+
+        self.array.write(index, value);
+        self.next = index;
+    }
+
+    /// Remove and return the oldest element in the ring buffer.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let cap = self.array.len();
+        let index = self.next;
+
+        // Safety: the slot at the head is initialized whenever `len > 0`,
+        // and is logically removed by the bookkeeping below.
+        let value = unsafe { self.array.assume_init_read(index) };
+        self.next = (index + 1) % cap;
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Remove and return the newest element in the ring buffer.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let index = self.back_index();
+
+        // Safety: the slot at `index` is initialized whenever `len > 0`,
+        // and is logically removed by the bookkeeping below.
+        let value = unsafe { self.array.assume_init_read(index) };
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Remove all elements from the ring buffer, dropping them in place.
+    pub fn clear(&mut self) {
+        // Safety: exactly `self.len` slots starting at the head are
+        // initialized, which is exactly what `drop_head` drops.
+        unsafe { self.drop_head(self.len) };
+        self.len = 0;
+        self.next = 0;
+    }
+
+    /// Physical index of the newest element, only valid while `len > 0`.
+    #[inline]
+    fn back_index(&self) -> usize {
+        let cap = self.array.len();
+        (self.next + self.len - 1) % cap
+    }
+
+    /// Drop the `n` oldest initialized elements, wrapping around the end of
+    /// the backing array as needed.
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure `n <= self.len`.
+    unsafe fn drop_head(&mut self, n: usize) {
+        if n == 0 {
+            return;
+        }
+
+        let cap = self.array.len();
+        let first = n.min(cap - self.next);
+        unsafe {
+            self.array.assume_init_drop(self.next, first);
+            if first < n {
+                self.array.assume_init_drop(0, n - first);
+            }
+        }
+    }
+
+    /// Remove a logical range of elements, returning them through an
+    /// iterator. Elements are ordered based on insertion order, like
+    /// [`RingArray::iter`].
+    ///
+    /// If the returned [`Drain`] is dropped before it is fully consumed,
+    /// the remaining elements in the range are dropped in place.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the range is out of bounds of the ring buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - Logical, 0-indexed range of elements to remove.
+    pub fn drain<R: std::ops::RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        use std::ops::Bound;
+
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n + 1,
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => self.len,
+        };
+        assert!(start <= end && end <= self.len, "drain range out of bounds");
+
+        Drain {
+            ring: self,
+            range: start..end,
+            cursor: start,
+        }
+    }
+
+    /// Translate a logical index (relative to the head at the time this
+    /// was captured) into a physical index into the backing array.
+    #[inline]
+    fn physical(&self, logical: usize) -> usize {
+        let cap = self.array.len();
+        (self.next + logical) % cap
+    }
+}
+
+/// Draining iterator returned by [`RingArray::drain`].
+///
+/// Removes a contiguous logical range of elements from the ring buffer,
+/// compacting the remaining elements back into one contiguous window.
+pub struct Drain<'a, T> {
+    ring: &'a mut RingArray<T>,
+    range: std::ops::Range<usize>,
+    cursor: usize,
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.cursor == self.range.end {
+            return None;
+        }
+
+        let index = self.ring.physical(self.cursor);
+        self.cursor += 1;
+
+        // Safety: `index` is one of the `self.ring.len` initialized slots
+        // at the time `drain` was called, and has not been read before.
+        Some(unsafe { self.ring.array.assume_init_read(index) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.range.end - self.cursor;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        // Finish dropping any elements the caller never pulled out.
+        for logical in self.cursor..self.range.end {
+            let index = self.ring.physical(logical);
+            // Safety: not yet read, and within the original drained range.
+            unsafe { self.ring.array.assume_init_drop(index, 1) };
+        }
+
+        // Close the gap left behind by sliding the shorter of the head or
+        // tail remainder over it, keeping the "len contiguous slots from
+        // the head" invariant intact.
+        let removed = self.range.end - self.range.start;
+        if removed == 0 {
+            return;
+        }
+
+        let head_len = self.range.start;
+        let tail_len = self.ring.len - self.range.end;
+
+        if head_len <= tail_len {
+            // Slide the head remainder forward into the freed slots.
+            for logical in (0..head_len).rev() {
+                let src = self.ring.physical(logical);
+                let dst = self.ring.physical(logical + removed);
+                // Safety: `src` is initialized and hasn't been moved yet;
+                // `dst` was either just vacated above or already moved.
+                let value = unsafe { self.ring.array.assume_init_read(src) };
+                self.ring.array.write(dst, value);
+            }
+            self.ring.next = self.ring.physical(removed);
+        } else {
+            // Slide the tail remainder backward into the freed slots.
+            for logical in self.range.end..self.ring.len {
+                let src = self.ring.physical(logical);
+                let dst = self.ring.physical(logical - removed);
+                // Safety: `src` is initialized and hasn't been moved yet;
+                // `dst` was either just vacated above or already moved.
+                let value = unsafe { self.ring.array.assume_init_read(src) };
+                self.ring.array.write(dst, value);
+            }
+        }
+
+        self.ring.len -= removed;
+    }
+}
+
+impl<T> Drop for RingArray<T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.clear();
+    }
 }
 
 impl<T: Copy> RingArray<T> {
@@ -71,39 +411,169 @@ impl<T: Copy> RingArray<T> {
     /// * `elems` - Elements to append into ring buffer.
     #[inline]
     pub fn copy_from_slice(&mut self, elems: &[T]) {
+        let cap = self.array.len();
         // Bail early if there is nothing to do.
-        if self.array.is_empty() || elems.is_empty() {
+        if cap == 0 || elems.is_empty() {
             return;
         }
 
         // Skip elements that will never be visible in ring buffer.
-        let cap = self.array.len();
         let start = elems.len().saturating_sub(cap);
+        let elems = &elems[start..];
 
-        // Copy elements into the ring buffer.
-        // If elements reach end of the ring buffer, we have to wrap around.
-        match elems[start..].split_at_checked(cap - self.next) {
+        // Evict the oldest elements about to be overwritten, if any.
+        let evicted = (self.len + elems.len()).saturating_sub(cap);
+        // Safety: `evicted` never exceeds `self.len`, since `elems.len()`
+        // is at most `cap` after the skip above.
+        unsafe { self.drop_head(evicted) };
+
+        // Copy elements into the ring buffer, starting right after the
+        // current newest element. If that reaches the end of the backing
+        // array, we have to wrap around.
+        let write_index = (self.next + self.len) % cap;
+        match elems.split_at_checked(cap - write_index) {
             None => {
-                self.array.copy_from_slice(self.next, elems);
-                self.next += elems.len();
-                self.len = std::cmp::min(self.len + elems.len(), cap);
+                self.array.copy_from_slice(write_index, elems);
             }
-
             Some((head, tail)) => {
-                self.array.copy_from_slice(self.next, head);
+                self.array.copy_from_slice(write_index, head);
                 self.array.copy_from_slice(0, tail);
-                self.next = tail.len();
-                self.len = cap;
             }
         };
+
+        self.next = (self.next + evicted) % cap;
+        self.len = self.len + elems.len() - evicted;
+    }
+}
+
+impl<T: Clone> RingArray<T> {
+    /// Append elements cloned from a slice into the ring buffer.
+    ///
+    /// Unlike [`RingArray::extend_from_iter`], the full length of `elems`
+    /// is known up front, so this takes the same wrap-aware two-segment
+    /// fast path as [`RingArray::copy_from_slice`] instead of writing one
+    /// element at a time: elements that could never remain visible are
+    /// skipped up front, and the rest are cloned directly into contiguous
+    /// runs of the backing array.
+    ///
+    /// # Arguments
+    ///
+    /// * `elems` - Elements to clone into the ring buffer.
+    pub fn extend_from_slice(&mut self, elems: &[T]) {
+        let cap = self.array.len();
+        // Bail early if there is nothing to do.
+        if cap == 0 || elems.is_empty() {
+            return;
+        }
+
+        // Skip elements that will never be visible in ring buffer.
+        let start = elems.len().saturating_sub(cap);
+        let elems = &elems[start..];
+
+        // Evict the oldest elements about to be overwritten, if any.
+        let evicted = (self.len + elems.len()).saturating_sub(cap);
+        // Safety: `evicted` never exceeds `self.len`, since `elems.len()`
+        // is at most `cap` after the skip above.
+        unsafe { self.drop_head(evicted) };
+
+        // Clone elements into the ring buffer, starting right after the
+        // current newest element. If that reaches the end of the backing
+        // array, we have to wrap around.
+        let write_index = (self.next + self.len) % cap;
+        match elems.split_at_checked(cap - write_index) {
+            None => {
+                self.array.write_from_slice(write_index, elems);
+            }
+            Some((head, tail)) => {
+                self.array.write_from_slice(write_index, head);
+                self.array.write_from_slice(0, tail);
+            }
+        };
+
+        self.next = (self.next + evicted) % cap;
+        self.len = self.len + elems.len() - evicted;
+    }
+}
+
+impl<T> RingArray<T> {
+    /// Append elements from an iterator, evicting from the front as needed
+    /// to make room for new elements.
+    ///
+    /// Equivalent to calling [`RingArray::push_back`] once per item: there
+    /// is no way to bulk-copy out of an arbitrary iterator, since its
+    /// elements are only available one at a time. For however many items
+    /// the iterator's `size_hint` guarantees up front (its lower bound),
+    /// items that could never remain visible are skipped without being
+    /// stored, and front eviction is batched instead of happening one
+    /// [`RingArray::push_back`] at a time; this also covers iterators that
+    /// report a non-zero lower bound but no exact length. Items beyond
+    /// that guarantee, or from an iterator reporting a lower bound of zero
+    /// (as `filter` adapters and channel receivers do), fall back to the
+    /// plain per-element path. If the source is already a slice, prefer
+    /// [`RingArray::extend_from_slice`], which reuses the same two-segment
+    /// bulk copy as [`RingArray::copy_from_slice`].
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - Elements to append into the ring buffer.
+    pub fn extend_from_iter<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter();
+        let lower = iter.size_hint().0;
+        self.extend_at_least(&mut iter, lower);
+        iter.for_each(|item| self.push_back(item));
+    }
+
+    /// Fast path for [`RingArray::extend_from_iter`]: batches the front
+    /// eviction needed for the first `lower` items, which `iter` is
+    /// guaranteed to yield per its `size_hint`, instead of evicting one
+    /// [`RingArray::push_back`] at a time. Whatever `iter` has left after
+    /// that is for the caller to push one at a time.
+    fn extend_at_least<I: Iterator<Item = T>>(&mut self, iter: &mut I, lower: usize) {
+        let cap = self.array.len();
+        if cap == 0 || lower == 0 {
+            return;
+        }
+
+        // Drop (without ever storing) elements that will never remain
+        // visible in the ring buffer.
+        let skip = lower.saturating_sub(cap);
+        for _ in 0..skip {
+            iter.next();
+        }
+        let n = lower - skip;
+        if n == 0 {
+            return;
+        }
+
+        // Evict the oldest elements about to be overwritten, if any.
+        let evicted = (self.len + n).saturating_sub(cap);
+        // Safety: `evicted` never exceeds `self.len`, since `n` is at most
+        // `cap` after the skip above.
+        unsafe { self.drop_head(evicted) };
+
+        let mut index = (self.next + self.len) % cap;
+        for item in iter.by_ref().take(n) {
+            self.array.write(index, item);
+            index = (index + 1) % cap;
+        }
+
+        self.next = (self.next + evicted) % cap;
+        self.len = self.len + n - evicted;
+    }
+}
+
+impl<T> Extend<T> for RingArray<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.extend_from_iter(iter);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::{Seqno, Zst};
-    use bolero::{check, generator};
+    use crate::tests::{Bytes, Seqno, Zst};
+    use bolero::{TypeGenerator, check, generator};
     use pastey::paste;
     use std::collections::VecDeque;
 
@@ -180,4 +650,271 @@ mod tests {
     }
 
     test_ring!(Zst, Seqno);
+
+    impl<T> Oracle<T> {
+        fn push_back(&mut self, value: T) {
+            if self.cap == 0 {
+                return;
+            }
+            if self.deque.len() == self.cap {
+                self.deque.pop_front();
+            }
+            self.deque.push_back(value);
+        }
+
+        fn push_front(&mut self, value: T) {
+            if self.cap == 0 {
+                return;
+            }
+            if self.deque.len() == self.cap {
+                self.deque.pop_back();
+            }
+            self.deque.push_front(value);
+        }
+    }
+
+    #[derive(Debug, Clone, TypeGenerator)]
+    enum Operation<T> {
+        PushBack(T),
+        PushFront(T),
+        PopBack,
+        PopFront,
+        Clear,
+    }
+
+    macro_rules! test_ring_deque {
+        ($($type:ty),*) => {
+            paste! {
+                $(
+                    #[test]
+                    fn [<test_ring_array_deque_ $type:snake>]() {
+                        check!()
+                            .with_max_len(MAX_SIZE)
+                            .with_generator((
+                                generator::produce::<usize>().with().bounds(0..1024),
+                                generator::produce::<Vec<Operation<$type>>>(),
+                            ))
+                            .for_each(|(capacity, operations)| {
+                                // Ring buffers for equivalence testing.
+                                let mut ring = RingArray::with_capacity(*capacity);
+                                let mut oracle = Oracle::with_capacity(*capacity);
+
+                                for operation in operations.iter().cloned() {
+                                    match operation {
+                                        Operation::PushBack(value) => {
+                                            ring.push_back(value.clone());
+                                            oracle.push_back(value);
+                                        }
+                                        Operation::PushFront(value) => {
+                                            ring.push_front(value.clone());
+                                            oracle.push_front(value);
+                                        }
+                                        Operation::PopBack => {
+                                            assert_eq!(ring.pop_back(), oracle.deque.pop_back());
+                                        }
+                                        Operation::PopFront => {
+                                            assert_eq!(ring.pop_front(), oracle.deque.pop_front());
+                                        }
+                                        Operation::Clear => {
+                                            ring.clear();
+                                            oracle.deque.clear();
+                                        }
+                                    }
+
+                                    // Make sure items are the same between the ring buffers.
+                                    let ring_items: Vec<_> = ring.iter().collect();
+                                    let oracle_items: Vec<_> = oracle.iter().collect();
+                                    assert_eq!(ring_items, oracle_items);
+                                    assert_eq!(ring.front(), oracle.iter().next());
+                                    assert_eq!(ring.back(), oracle.deque.back());
+                                }
+                            });
+                    }
+                )*
+            }
+        };
+    }
+
+    test_ring_deque!(Zst, Seqno, Bytes);
+
+    #[test]
+    fn test_try_with_capacity_matches_infallible() {
+        let mut ring = RingArray::try_with_capacity(4).unwrap();
+        ring.push_back(Seqno(1));
+        ring.push_back(Seqno(2));
+        assert_eq!(
+            ring.iter().copied().collect::<Vec<_>>(),
+            vec![Seqno(1), Seqno(2)]
+        );
+
+        let mut infallible = RingArray::with_capacity(4);
+        infallible.push_back(Seqno(1));
+        infallible.push_back(Seqno(2));
+        assert!(ring.iter().eq(infallible.iter()));
+    }
+
+    #[test]
+    fn test_drain_removes_range_and_preserves_order() {
+        let mut ring = RingArray::with_capacity(5);
+        ring.push_back(Seqno(1));
+        ring.push_back(Seqno(2));
+        ring.push_back(Seqno(3));
+        ring.push_back(Seqno(4));
+        ring.push_back(Seqno(5));
+
+        let drained: Vec<_> = ring.drain(1..3).collect();
+        assert_eq!(drained, vec![Seqno(2), Seqno(3)]);
+        assert_eq!(
+            ring.iter().copied().collect::<Vec<_>>(),
+            vec![Seqno(1), Seqno(4), Seqno(5)]
+        );
+    }
+
+    #[test]
+    fn test_drain_dropped_early_still_removes_range() {
+        let mut ring = RingArray::with_capacity(5);
+        ring.push_back(Seqno(1));
+        ring.push_back(Seqno(2));
+        ring.push_back(Seqno(3));
+        ring.push_back(Seqno(4));
+
+        drop(ring.drain(1..3));
+        assert_eq!(
+            ring.iter().copied().collect::<Vec<_>>(),
+            vec![Seqno(1), Seqno(4)]
+        );
+    }
+
+    macro_rules! test_ring_drain {
+        ($($type:ty),*) => {
+            paste! {
+                $(
+                    #[test]
+                    fn [<test_ring_array_drain_ $type:snake>]() {
+                        check!()
+                            .with_max_len(MAX_SIZE)
+                            .with_generator((
+                                generator::produce::<usize>().with().bounds(1..1024),
+                                generator::produce::<Vec<$type>>(),
+                                generator::produce::<(usize, usize)>(),
+                            ))
+                            .for_each(|(capacity, items, (a, b))| {
+                                let mut ring = RingArray::with_capacity(*capacity);
+                                let mut oracle = Oracle::with_capacity(*capacity);
+
+                                for item in items.iter().cloned() {
+                                    ring.push_back(item.clone());
+                                    oracle.push_back(item);
+                                }
+
+                                let len = oracle.deque.len();
+                                if len == 0 {
+                                    return;
+                                }
+
+                                let start = a % (len + 1);
+                                let end = start + (if len > start { b % (len - start + 1) } else { 0 });
+
+                                let drained: Vec<_> = ring.drain(start..end).collect();
+                                let expected: Vec<_> = oracle.deque.drain(start..end).collect();
+                                assert_eq!(drained, expected);
+
+                                let ring_items: Vec<_> = ring.iter().collect();
+                                let oracle_items: Vec<_> = oracle.iter().collect();
+                                assert_eq!(ring_items, oracle_items);
+                            });
+                    }
+                )*
+            }
+        };
+    }
+
+    test_ring_drain!(Zst, Seqno, Bytes);
+
+    #[test]
+    fn test_iter_mut_rewrites_elements_across_wrap() {
+        let mut ring = RingArray::with_capacity(3);
+        ring.push_back(Seqno(1));
+        ring.push_back(Seqno(2));
+        ring.push_back(Seqno(3));
+        // Wraps the buffer: head is no longer at physical index 0.
+        ring.push_back(Seqno(4));
+
+        for seqno in ring.iter_mut() {
+            seqno.0 *= 10;
+        }
+
+        assert_eq!(
+            ring.iter().copied().collect::<Vec<_>>(),
+            vec![Seqno(20), Seqno(30), Seqno(40)]
+        );
+    }
+
+    /// Iterator adapter that reports only a lower bound (no upper bound),
+    /// to exercise [`RingArray::extend_from_iter`]'s
+    /// fast path for iterators that can't report an exact length.
+    struct LowerBoundOnly<I>(I);
+
+    impl<I: Iterator> Iterator for LowerBoundOnly<I> {
+        type Item = I::Item;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.0.next()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.0.size_hint().0, None)
+        }
+    }
+
+    macro_rules! test_ring_extend {
+        ($($type:ty),*) => {
+            paste! {
+                $(
+                    #[test]
+                    fn [<test_ring_array_extend_ $type:snake>]() {
+                        check!()
+                            .with_max_len(MAX_SIZE)
+                            .with_generator((
+                                generator::produce::<usize>().with().bounds(0..1024),
+                                generator::produce::<Vec<Vec<$type>>>(),
+                            ))
+                            .for_each(|(capacity, operations)| {
+                                let mut ring = RingArray::with_capacity(*capacity);
+                                let mut oracle = Oracle::with_capacity(*capacity);
+
+                                for items in operations {
+                                    // Exercise the exact-size fast path (Vec's iterator
+                                    // reports an exact size_hint), the lower-bound-only
+                                    // fast path (an iterator with no upper bound), the
+                                    // fully generic fallback (a filter adapter reports
+                                    // a lower bound of zero), and the slice fast path,
+                                    // in rotation.
+                                    match items.len() % 4 {
+                                        0 => ring.extend_from_iter(items.iter().cloned()),
+                                        1 => {
+                                            ring.extend_from_iter(LowerBoundOnly(
+                                                items.iter().cloned(),
+                                            ));
+                                        }
+                                        2 => {
+                                            let filtered = items.iter().cloned().filter(|_| true);
+                                            ring.extend_from_iter(filtered);
+                                        }
+                                        _ => ring.extend_from_slice(items),
+                                    }
+                                    oracle.extend_from_slice(items);
+
+                                    let ring_items: Vec<_> = ring.iter().collect();
+                                    let oracle_items: Vec<_> = oracle.iter().collect();
+                                    assert_eq!(ring_items, oracle_items);
+                                }
+                            });
+                    }
+                )*
+            }
+        };
+    }
+
+    test_ring_extend!(Zst, Seqno, Bytes);
 }
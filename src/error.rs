@@ -0,0 +1,33 @@
+//! Errors produced by fallible allocating constructors.
+
+use std::alloc::Layout;
+use std::fmt;
+
+/// Error returned when a fallible allocating constructor cannot satisfy a
+/// request, instead of panicking like its infallible counterpart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// The requested layout would overflow `isize::MAX` bytes.
+    CapacityOverflow,
+
+    /// The global allocator could not satisfy the given layout.
+    AllocError(Layout),
+}
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CapacityOverflow => {
+                write!(f, "capacity overflow while computing allocation layout")
+            }
+            Self::AllocError(layout) => write!(
+                f,
+                "allocator failed to allocate {} byte(s) (align {})",
+                layout.size(),
+                layout.align()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TryReserveError {}
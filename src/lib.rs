@@ -23,11 +23,26 @@
 //! ## RingArray
 //!
 //! A [`RingArray`] is a simple ring buffer that uses a [`LazyArray`] to store elements.
+//!
+//! ## DynArray
+//!
+//! A [`DynArray`] is a growable array that uses a [`LazyArray`] to store elements, filling
+//! the gap between the fixed-length [`Array`] and a runtime-resizable owned buffer.
+//!
+//! ## Fallible allocation
+//!
+//! Allocating constructors come in two flavours: an infallible one that panics
+//! on allocation failure, and a `try_*` one that instead returns a
+//! [`TryReserveError`]. The infallible constructors are thin wrappers around the
+//! fallible ones.
 
 pub(crate) mod array;
+pub(crate) mod error;
 
-pub use array::ring::RingArray;
+pub use array::dynamic::DynArray;
+pub use array::ring::{Drain, RingArray};
 pub use array::{Array, LazyArray};
+pub use error::TryReserveError;
 
 #[cfg(test)]
 pub(crate) mod tests {
@@ -35,7 +50,7 @@ pub(crate) mod tests {
 
     /// Test with sized trivially droppable type.
     #[derive(Debug, Copy, Clone, PartialEq, Eq, TypeGenerator)]
-    pub(crate) struct Seqno(u64);
+    pub(crate) struct Seqno(pub(crate) u64);
 
     /// Test with type that is not trivially droppable.
     #[derive(Debug, Clone, PartialEq, Eq, TypeGenerator)]